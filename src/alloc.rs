@@ -0,0 +1,89 @@
+//! Tracks memory committed to the OCR hot path (decoding embedded PDF
+//! images and running Tesseract over them) so `dispatch` can bail out
+//! with a clean `Err` before expensive work, instead of growing
+//! unbounded when many `te` processes run side by side.
+//!
+//! This deliberately does *not* hook the global allocator: doing so means
+//! an allocation failure anywhere in the process (zip/xml parsing, serde,
+//! plain `String`/`Vec` growth in the non-OCR extractors, ...) trips
+//! Rust's `handle_alloc_error` and aborts the whole process instead of
+//! producing the `Error` JSON variant. Scoping the budget to the OCR
+//! buffers we actually reserve keeps the failure mode clean.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+/// Ceiling in bytes, read once from `TE_MAX_MEMORY_BYTES` (default 2 GiB).
+fn limit_bytes() -> usize {
+    static LIMIT: OnceLock<usize> = OnceLock::new();
+
+    *LIMIT.get_or_init(|| {
+        std::env::var("TE_MAX_MEMORY_BYTES")
+            //
+            .ok()
+            //
+            .and_then(|value| value.parse().ok())
+            //
+            .unwrap_or(2 * 1024 * 1024 * 1024)
+    })
+}
+
+pub(crate) struct MemoryBudget {
+    used: AtomicUsize,
+}
+
+/// An in-flight reservation against the budget; releases itself on drop
+/// so call sites can't forget to give the bytes back.
+pub(crate) struct Reservation<'a> {
+    budget: &'a MemoryBudget,
+    size: usize,
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        self.budget.used.fetch_sub(self.size, Ordering::SeqCst);
+    }
+}
+
+impl MemoryBudget {
+    pub(crate) const fn new() -> Self {
+        Self {
+            used: AtomicUsize::new(0),
+        }
+    }
+
+    /// Bytes currently reserved against the ceiling.
+    pub(crate) fn allocated(&self) -> usize {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    /// Whether the configured ceiling has already been reached, for call
+    /// sites that want to bail out of expensive work before even trying
+    /// to reserve (e.g. before starting OCR).
+    pub(crate) fn budget_exhausted(&self) -> bool {
+        self.allocated() >= limit_bytes()
+    }
+
+    /// Reserves `size` bytes against the ceiling, returning a guard that
+    /// gives them back on drop, or `None` if the ceiling would be
+    /// exceeded.
+    pub(crate) fn try_reserve(&self, size: usize) -> Option<Reservation<'_>> {
+        loop {
+            let used = self.used.load(Ordering::Relaxed);
+            if used + size > limit_bytes() {
+                return None;
+            }
+
+            if self
+                //
+                .used
+                //
+                .compare_exchange_weak(used, used + size, Ordering::SeqCst, Ordering::Relaxed)
+                //
+                .is_ok()
+            {
+                return Some(Reservation { budget: self, size });
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Content-addressed, on-disk cache for extraction results.
+//!
+//! The key is a blake3 hash of the raw input bytes combined with
+//! [`EXTRACTOR_VERSION`], so bumping the version after any change to
+//! extraction logic self-invalidates every entry written by the old code.
+//!
+//! Only successful extractions are cached. Failures (including transient
+//! ones like `"memory limit exceeded"`) are content-hash-keyed the same
+//! as successes, so caching an `Err` would make it permanent for that
+//! exact input even after whatever caused it (e.g. memory pressure)
+//! clears up.
+
+use std::path::PathBuf;
+
+/// Bump this whenever extraction logic changes.
+const EXTRACTOR_VERSION: u32 = 1;
+
+fn cache_dir() -> PathBuf {
+    if let Ok(dir) = std::env::var("XDG_CACHE_HOME") {
+        return PathBuf::from(dir).join("te");
+    }
+
+    std::env::var("HOME")
+        //
+        .map(|home| PathBuf::from(home).join(".cache").join("te"))
+        //
+        .unwrap_or_else(|_| PathBuf::from(".cache/te"))
+}
+
+pub(crate) fn key_for(input: &[u8]) -> String {
+    let hash = blake3::hash(input);
+
+    format!("{}-v{EXTRACTOR_VERSION}", hash.to_hex())
+}
+
+pub(crate) fn get(key: &str) -> Option<String> {
+    std::fs::read_to_string(cache_dir().join(key)).ok()
+}
+
+/// Writes `text` to a temp file in `dir` and renames it into place, so a
+/// process killed mid-write (e.g. OOM-killed) can never leave a truncated
+/// file behind for `get` to read back as valid output.
+pub(crate) fn put(key: &str, text: &str) {
+    let dir = cache_dir();
+
+    if std::fs::create_dir_all(&dir).is_err() {
+        return;
+    }
+
+    let tmp_path = dir.join(format!("{key}.tmp.{}", std::process::id()));
+
+    if std::fs::write(&tmp_path, text).is_err() {
+        return;
+    }
+
+    let _ = std::fs::rename(&tmp_path, dir.join(key));
+}
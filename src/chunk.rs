@@ -0,0 +1,204 @@
+//! Splits extracted text into overlapping windows sized for embedding
+//! models, so `te` can feed a RAG ingestion pipeline directly instead of
+//! every consumer reimplementing windowing and offset tracking.
+
+use serde::Serialize;
+
+const DEFAULT_CHUNK_LEN: usize = 1024;
+
+const DEFAULT_CHUNK_OVERLAP: usize = 128;
+
+#[derive(Serialize)]
+pub(crate) struct Chunk {
+    index: usize,
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+pub(crate) struct ChunkConfig {
+    len: usize,
+    overlap: usize,
+}
+
+impl ChunkConfig {
+    /// Returns `Some` when chunked output was requested via `--chunk` or
+    /// the `TE_CHUNK` env var, with window size/overlap read from
+    /// `TE_CHUNK_LEN`/`TE_CHUNK_OVERLAP` (falling back to sane defaults).
+    pub(crate) fn from_env() -> Option<Self> {
+        let requested = std::env::var("TE_CHUNK").is_ok()
+            //
+            || std::env::args().any(|arg| arg == "--chunk");
+
+        if !requested {
+            return None;
+        }
+
+        let len = std::env::var("TE_CHUNK_LEN")
+            //
+            .ok()
+            //
+            .and_then(|value| value.parse().ok())
+            //
+            .unwrap_or(DEFAULT_CHUNK_LEN);
+
+        let overlap = std::env::var("TE_CHUNK_OVERLAP")
+            //
+            .ok()
+            //
+            .and_then(|value| value.parse().ok())
+            //
+            .unwrap_or(DEFAULT_CHUNK_OVERLAP)
+            //
+            .min(len.saturating_sub(1));
+
+        Some(Self { len, overlap })
+    }
+}
+
+pub(crate) fn chunk_text(text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+    let mut chunks = Vec::new();
+
+    let mut start = 0;
+    let mut index = 0;
+
+    while start < text.len() {
+        let target_end = floor_char_boundary(text, (start + config.len).min(text.len()));
+
+        let end = if target_end < text.len() {
+            find_boundary(text, start, target_end)
+        } else {
+            target_end
+        };
+
+        chunks.push(Chunk {
+            index,
+            start,
+            end,
+            text: text[start..end].to_string(),
+        });
+
+        index += 1;
+
+        if end >= text.len() {
+            break;
+        }
+
+        start = ceil_char_boundary(text, (end.saturating_sub(config.overlap)).max(start + 1));
+    }
+
+    chunks
+}
+
+/// Looks for a paragraph or sentence boundary near `target_end`, searching
+/// backward within the trailing fifth of the window, so a chunk doesn't
+/// cut mid-sentence when a natural break is close by.
+fn find_boundary(text: &str, start: usize, target_end: usize) -> usize {
+    let lookback = (target_end - start) / 5;
+    let window_start = floor_char_boundary(text, target_end.saturating_sub(lookback).max(start));
+
+    let window = &text[window_start..target_end];
+
+    if let Some(pos) = window.rfind("\n\n") {
+        return window_start + pos + "\n\n".len();
+    }
+
+    for separator in ["\n", ". ", "! ", "? "] {
+        if let Some(pos) = window.rfind(separator) {
+            return window_start + pos + separator.len();
+        }
+    }
+
+    target_end
+}
+
+fn floor_char_boundary(text: &str, mut idx: usize) -> usize {
+    while idx > 0 && !text.is_char_boundary(idx) {
+        idx -= 1;
+    }
+
+    idx
+}
+
+fn ceil_char_boundary(text: &str, mut idx: usize) -> usize {
+    while idx < text.len() && !text.is_char_boundary(idx) {
+        idx += 1;
+    }
+
+    idx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_multi_byte_utf8_text_without_panicking() {
+        // 400 * 3 bytes = 1200 bytes, putting the default 1024-byte window
+        // edge squarely inside a character.
+        let text = "€".repeat(400);
+        let config = ChunkConfig {
+            len: 1024,
+            overlap: 128,
+        };
+
+        let chunks = chunk_text(&text, &config);
+
+        assert!(!chunks.is_empty());
+
+        for chunk in &chunks {
+            assert!(text.is_char_boundary(chunk.start));
+            assert!(text.is_char_boundary(chunk.end));
+            assert_eq!(text[chunk.start..chunk.end], chunk.text);
+        }
+    }
+
+    #[test]
+    fn overlapping_chunks_cover_the_whole_text() {
+        let text = "a".repeat(500);
+        let config = ChunkConfig {
+            len: 100,
+            overlap: 20,
+        };
+
+        let chunks = chunk_text(&text, &config);
+
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks.first().unwrap().start, 0);
+        assert_eq!(chunks.last().unwrap().end, text.len());
+
+        for pair in chunks.windows(2) {
+            assert!(pair[1].start < pair[0].end, "chunks should overlap");
+        }
+    }
+
+    #[test]
+    fn small_chunk_len_and_overlap_still_make_forward_progress() {
+        let text = "hello world, this is a test of small chunk windows";
+        let config = ChunkConfig { len: 8, overlap: 4 };
+
+        let chunks = chunk_text(text, &config);
+
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks.last().unwrap().end, text.len());
+
+        for (i, chunk) in chunks.iter().enumerate() {
+            assert_eq!(chunk.index, i);
+            assert!(chunk.start < chunk.end);
+        }
+    }
+
+    #[test]
+    fn breaks_on_a_paragraph_boundary_near_the_window_edge() {
+        let text = format!("{}\n\n{}", "a".repeat(90), "b".repeat(90));
+        let config = ChunkConfig {
+            len: 100,
+            overlap: 10,
+        };
+
+        let chunks = chunk_text(&text, &config);
+
+        assert_eq!(chunks[0].end, 92);
+        assert!(chunks[0].text.ends_with("\n\n"));
+    }
+}
@@ -0,0 +1,162 @@
+use std::cell::Cell;
+use std::io::{Cursor, Read};
+
+use super::common::open_zip;
+use super::Extractor;
+
+/// Caps how many archives-within-archives `append_entry_text` will follow
+/// before giving up, so a zip-in-a-zip-in-a-zip can't recurse through
+/// `dispatch` unboundedly and blow the stack.
+const MAX_ARCHIVE_DEPTH: u32 = 4;
+
+/// Mirrors `dispatch`'s own input-size ceiling, checked *before* an entry
+/// is decompressed into memory so a high-ratio zip/tar bomb can't force a
+/// multi-GB transient allocation ahead of that later guard.
+const MAX_ENTRY_SIZE: u64 = 5 * 1024 * 1024;
+
+thread_local! {
+    static DEPTH: Cell<u32> = const { Cell::new(0) };
+}
+
+/// RAII guard that increments the thread-local nesting depth on creation
+/// and decrements it on drop, so every early-return path in
+/// `append_entry_text` still releases its level.
+struct DepthGuard;
+
+impl DepthGuard {
+    fn enter() -> Option<Self> {
+        let depth = DEPTH.with(Cell::get);
+        if depth >= MAX_ARCHIVE_DEPTH {
+            return None;
+        }
+
+        DEPTH.with(|d| d.set(depth + 1));
+
+        Some(Self)
+    }
+}
+
+impl Drop for DepthGuard {
+    fn drop(&mut self) {
+        DEPTH.with(|d| d.set(d.get() - 1));
+    }
+}
+
+pub(crate) struct ArchiveExtractor;
+
+impl Extractor for ArchiveExtractor {
+    fn can_handle(&self, mime: &str, _input: &[u8]) -> bool {
+        matches!(mime, "application/zip" | "application/x-tar")
+    }
+
+    fn extract(&self, input: &[u8]) -> Result<String, String> {
+        let Some(kind) = infer::get(input) else {
+            return Err("unknown file kind".to_string());
+        };
+
+        let mut buf = String::with_capacity(4096);
+
+        match kind.mime_type() {
+            //
+            "application/zip" => extract_zip(input, &mut buf)?,
+            //
+            "application/x-tar" => extract_tar(input, &mut buf)?,
+            //
+            other => return Err(format!("unsupported file type: {other}")),
+        }
+
+        let effective_len = buf.trim().len();
+        if effective_len < crate::MIN_TEXT_LEN {
+            return Err(format!("invalid text length: {effective_len}"));
+        }
+
+        Ok(buf)
+    }
+}
+
+fn extract_zip(input: &[u8], buf: &mut String) -> Result<(), String> {
+    let mut archive = open_zip(input)?;
+
+    for index in 0..archive.len() {
+        let mut file = archive
+            //
+            .by_index(index)
+            //
+            .map_err(|e| format!("failed to read zip entry: {e:?}"))?;
+
+        if file.is_dir() {
+            continue;
+        }
+
+        if file.size() > MAX_ENTRY_SIZE {
+            continue;
+        }
+
+        let mut data = Vec::new();
+        file.take(MAX_ENTRY_SIZE)
+            //
+            .read_to_end(&mut data)
+            //
+            .map_err(|e| format!("failed to read zip entry: {e:?}"))?;
+
+        append_entry_text(buf, &data)?;
+    }
+
+    Ok(())
+}
+
+fn extract_tar(input: &[u8], buf: &mut String) -> Result<(), String> {
+    let mut archive = tar::Archive::new(Cursor::new(input));
+
+    let entries = archive
+        //
+        .entries()
+        //
+        .map_err(|e| format!("failed to read input as tar: {e:?}"))?;
+
+    for entry in entries {
+        let mut entry = entry
+            //
+            .map_err(|e| format!("failed to read tar entry: {e:?}"))?;
+
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+
+        let declared_size = entry.header().size().unwrap_or(u64::MAX);
+        if declared_size > MAX_ENTRY_SIZE {
+            continue;
+        }
+
+        let mut data = Vec::new();
+        entry
+            .take(MAX_ENTRY_SIZE)
+            //
+            .read_to_end(&mut data)
+            //
+            .map_err(|e| format!("failed to read tar entry: {e:?}"))?;
+
+        append_entry_text(buf, &data)?;
+    }
+
+    Ok(())
+}
+
+fn append_entry_text(buf: &mut String, data: &[u8]) -> Result<(), String> {
+    let Some(_guard) = DepthGuard::enter() else {
+        return Err("archive nesting too deep".to_string());
+    };
+
+    let Ok(text) = crate::dispatch(data) else {
+        return Ok(());
+    };
+
+    buf.push_str(&text);
+    if buf.len() > crate::MAX_TEXT_LEN {
+        return Err(format!("invalid text length: {}", buf.len()));
+    }
+
+    buf.push('\n');
+
+    Ok(())
+}
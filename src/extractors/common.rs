@@ -0,0 +1,78 @@
+use std::io::{Cursor, Read};
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use zip::ZipArchive;
+
+pub(crate) fn open_zip(input: &[u8]) -> Result<ZipArchive<Cursor<&[u8]>>, String> {
+    ZipArchive::new(Cursor::new(input))
+        //
+        .map_err(|e| format!("failed to read input as zip: {e:?}"))
+}
+
+pub(crate) fn read_zip_entry(
+    archive: &mut ZipArchive<Cursor<&[u8]>>,
+    name: &str,
+) -> Option<Vec<u8>> {
+    let mut file = archive.by_name(name).ok()?;
+
+    let mut data = Vec::new();
+    file.read_to_end(&mut data).ok()?;
+
+    Some(data)
+}
+
+/// Concatenates every text node found in `xml` into `buf`, inserting a
+/// newline after the close of any tag named in `break_tags` (e.g. a
+/// paragraph or table row) to keep the output readable.
+pub(crate) fn extract_xml_text(
+    xml: &[u8],
+    break_tags: &[&str],
+    buf: &mut String,
+) -> Result<(), String> {
+    let mut reader = Reader::from_reader(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut xml_buf = Vec::new();
+
+    loop {
+        match reader
+            //
+            .read_event_into(&mut xml_buf)
+            //
+            .map_err(|e| format!("failed to parse xml: {e:?}"))?
+        {
+            Event::Text(text) | Event::CData(text) => {
+                let text = text
+                    //
+                    .decode()
+                    //
+                    .map_err(|e| format!("failed to decode xml text: {e:?}"))?;
+
+                buf.push_str(&text);
+
+                if buf.len() > crate::MAX_TEXT_LEN {
+                    return Err(format!("invalid text length: {}", buf.len()));
+                }
+            }
+
+            Event::End(end) => {
+                let name = end.name();
+                let name = String::from_utf8_lossy(name.as_ref());
+
+                if break_tags.iter().any(|tag| *tag == name) {
+                    buf.push('\n');
+                }
+            }
+
+            Event::Eof => break,
+
+            _ => (),
+        }
+
+        xml_buf.clear();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use super::Extractor;
+
+pub(crate) struct DocxExtractor;
+
+impl Extractor for DocxExtractor {
+    fn can_handle(&self, mime: &str, _input: &[u8]) -> bool {
+        mime == "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
+    }
+
+    fn extract(&self, input: &[u8]) -> Result<String, String> {
+        extract_docx(input)
+    }
+}
+
+fn extract_docx(input: &[u8]) -> Result<String, String> {
+    use docx_rs::{
+        DocumentChild, ParagraphChild, RunChild, TableCellContent, TableChild, TableRowChild,
+    };
+
+    fn extract_paragraph(buf: &mut String, paragraph: &[ParagraphChild]) -> Result<(), String> {
+        for child in paragraph {
+            if let ParagraphChild::Run(run) = child {
+                for child in &run.children {
+                    match child {
+                        RunChild::Text(text) => {
+                            buf.push_str(&text.text);
+                            if buf.len() > crate::MAX_TEXT_LEN {
+                                return Err(format!("invalid text length: {}", buf.len()));
+                            }
+                        }
+
+                        RunChild::Break(_) => buf.push('\n'),
+
+                        RunChild::Tab(_) => buf.push('\t'),
+
+                        _ => (),
+                    }
+                }
+            }
+        }
+
+        buf.push('\n');
+
+        Ok(())
+    }
+
+    let docx = docx_rs::read_docx(input)
+        //
+        .map_err(|e| format!("failed to read input as docx: {e:?}"))?;
+
+    let mut buf = String::with_capacity(4096);
+
+    for node in &docx.document.children {
+        match node {
+            DocumentChild::Paragraph(paragraph) => {
+                extract_paragraph(&mut buf, &paragraph.children)?;
+            }
+
+            DocumentChild::Table(table) => {
+                for TableChild::TableRow(row) in &table.rows {
+                    for TableRowChild::TableCell(cell) in &row.cells {
+                        for child in &cell.children {
+                            match child {
+                                TableCellContent::Paragraph(paragraph) => {
+                                    extract_paragraph(&mut buf, &paragraph.children)?;
+                                }
+
+                                _ => (),
+                            }
+                        }
+
+                        buf.push('\t');
+                    }
+                }
+
+                buf.push('\n');
+            }
+
+            _ => (),
+        }
+    }
+
+    let effective_len = buf.trim().len();
+    if effective_len < crate::MIN_TEXT_LEN {
+        return Err(format!("invalid text length: {effective_len}"));
+    }
+
+    Ok(buf)
+}
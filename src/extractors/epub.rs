@@ -0,0 +1,43 @@
+use super::common::{extract_xml_text, open_zip, read_zip_entry};
+use super::Extractor;
+
+pub(crate) struct EpubExtractor;
+
+impl Extractor for EpubExtractor {
+    fn can_handle(&self, mime: &str, _input: &[u8]) -> bool {
+        mime == "application/epub+zip"
+    }
+
+    fn extract(&self, input: &[u8]) -> Result<String, String> {
+        let mut archive = open_zip(input)?;
+
+        let mut document_names: Vec<String> = archive
+            //
+            .file_names()
+            //
+            .filter(|name| name.ends_with(".xhtml") || name.ends_with(".html"))
+            //
+            .map(|name| name.to_string())
+            //
+            .collect();
+
+        document_names.sort();
+
+        let mut buf = String::with_capacity(4096);
+
+        for name in document_names {
+            let Some(xml) = read_zip_entry(&mut archive, &name) else {
+                continue;
+            };
+
+            extract_xml_text(&xml, &["p", "div", "br", "h1", "h2", "h3"], &mut buf)?;
+        }
+
+        let effective_len = buf.trim().len();
+        if effective_len < crate::MIN_TEXT_LEN {
+            return Err(format!("invalid text length: {effective_len}"));
+        }
+
+        Ok(buf)
+    }
+}
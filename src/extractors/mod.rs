@@ -0,0 +1,33 @@
+mod archive;
+mod common;
+mod docx;
+mod epub;
+mod opendocument;
+mod pdf;
+mod pptx;
+mod xlsx;
+
+pub(crate) trait Extractor {
+    fn can_handle(&self, mime: &str, input: &[u8]) -> bool;
+
+    fn extract(&self, input: &[u8]) -> Result<String, String>;
+}
+
+pub(crate) fn registry() -> Vec<Box<dyn Extractor>> {
+    vec![
+        //
+        Box::new(pdf::PdfExtractor),
+        //
+        Box::new(docx::DocxExtractor),
+        //
+        Box::new(pptx::PptxExtractor),
+        //
+        Box::new(xlsx::XlsxExtractor),
+        //
+        Box::new(opendocument::OpenDocumentExtractor),
+        //
+        Box::new(epub::EpubExtractor),
+        //
+        Box::new(archive::ArchiveExtractor),
+    ]
+}
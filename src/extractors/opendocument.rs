@@ -0,0 +1,38 @@
+use super::common::{extract_xml_text, open_zip, read_zip_entry};
+use super::Extractor;
+
+pub(crate) struct OpenDocumentExtractor;
+
+impl Extractor for OpenDocumentExtractor {
+    fn can_handle(&self, mime: &str, _input: &[u8]) -> bool {
+        matches!(
+            mime,
+            "application/vnd.oasis.opendocument.text"
+                | "application/vnd.oasis.opendocument.presentation"
+                | "application/vnd.oasis.opendocument.spreadsheet"
+        )
+    }
+
+    fn extract(&self, input: &[u8]) -> Result<String, String> {
+        let mut archive = open_zip(input)?;
+
+        let xml = read_zip_entry(&mut archive, "content.xml")
+            //
+            .ok_or_else(|| "missing content.xml".to_string())?;
+
+        let mut buf = String::with_capacity(4096);
+
+        extract_xml_text(
+            &xml,
+            &["text:p", "text:h", "table:table-row"],
+            &mut buf,
+        )?;
+
+        let effective_len = buf.trim().len();
+        if effective_len < crate::MIN_TEXT_LEN {
+            return Err(format!("invalid text length: {effective_len}"));
+        }
+
+        Ok(buf)
+    }
+}
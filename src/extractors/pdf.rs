@@ -0,0 +1,140 @@
+use super::Extractor;
+
+pub(crate) struct PdfExtractor;
+
+impl Extractor for PdfExtractor {
+    fn can_handle(&self, mime: &str, _input: &[u8]) -> bool {
+        mime == "application/pdf"
+    }
+
+    fn extract(&self, input: &[u8]) -> Result<String, String> {
+        extract_pdf(input)
+    }
+}
+
+fn extract_pdf(input: &[u8]) -> Result<String, String> {
+    use lopdf::{Document, Object};
+
+    use tesseract_plumbing::TessBaseApi;
+
+    use leptonica_plumbing::Pix;
+
+    let document = Document::load_mem(input)
+        //
+        .map_err(|e| format!("failed to read input as pdf: {e:?}"))?;
+
+    let mut buf = document
+        //
+        .extract_text(
+            //
+            &document
+                .get_pages()
+                //
+                .into_keys()
+                //
+                .collect::<Vec<_>>(),
+        )
+        //
+        .map_err(|e| format!("failed to extract text: {e:?}"))?;
+
+    let effective_len = buf.trim().len();
+    if effective_len < crate::MIN_TEXT_LEN {
+        buf.clear();
+
+        if crate::MEMORY_BUDGET.budget_exhausted() {
+            return Err("memory limit exceeded".to_string());
+        }
+
+        let mut tesseract = TessBaseApi::create();
+        if let Err(e) = tesseract.init_4(
+            //
+            Some(c"/usr/share/tesseract-ocr/5/tessdata"),
+            //
+            Some(c"eng"),
+            //
+            tesseract_sys::TessOcrEngineMode_OEM_LSTM_ONLY,
+        ) {
+            return Err(format!("failed to initialize ocr: {e:?}"));
+        }
+
+        for (object_id, _) in document.objects.iter() {
+            if let Ok(object) = document.get_object(*object_id) {
+                if let Object::Stream(stream) = object {
+                    if let Ok(subtype) = stream.dict.get(b"Subtype") {
+                        if let Object::Name(name) = subtype {
+                            if name == b"Image" {
+                                let data = &stream.content;
+                                if data.is_empty() {
+                                    continue;
+                                }
+
+                                // Cheap pre-decode gate on the compressed
+                                // stream; `preprocess` below re-sizes this
+                                // to the actual decoded buffer, which can
+                                // be far larger.
+                                let Some(decode_reservation) =
+                                    crate::MEMORY_BUDGET.try_reserve(data.len())
+                                else {
+                                    return Err("memory limit exceeded".to_string());
+                                };
+
+                                let pix = match Pix::read_mem(&data) {
+                                    Ok(pix) => pix,
+
+                                    Err(_) => {
+                                        eprintln!("failed to read image data");
+
+                                        continue;
+                                    }
+                                };
+
+                                let (pix, _reservation) = if crate::leptonica::enabled() {
+                                    drop(decode_reservation);
+
+                                    match crate::leptonica::preprocess(pix) {
+                                        Ok(result) => result,
+
+                                        Err(e) => return Err(e),
+                                    }
+                                } else {
+                                    (pix, decode_reservation)
+                                };
+
+                                tesseract.set_image_2(&pix);
+
+                                match tesseract.get_utf8_text() {
+                                    Ok(text) => match text.as_ref().to_str() {
+                                        Ok(text_str) => {
+                                            buf.push_str(text_str);
+                                            if buf.len() > crate::MAX_TEXT_LEN {
+                                                return Err(format!(
+                                                    "invalid text length: {}",
+                                                    buf.len()
+                                                ));
+                                            }
+
+                                            buf.push('\n');
+                                        }
+                                        Err(e) => {
+                                            eprintln!("failed to extract text: {e:?}");
+                                        }
+                                    },
+                                    Err(e) => {
+                                        eprintln!("failed to extract text: {e:?}");
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let effective_len = buf.trim().len();
+    if effective_len < crate::MIN_TEXT_LEN || effective_len > crate::MAX_TEXT_LEN {
+        Err(format!("invalid text length: {effective_len}"))
+    } else {
+        Ok(buf)
+    }
+}
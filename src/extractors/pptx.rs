@@ -0,0 +1,43 @@
+use super::common::{extract_xml_text, open_zip, read_zip_entry};
+use super::Extractor;
+
+pub(crate) struct PptxExtractor;
+
+impl Extractor for PptxExtractor {
+    fn can_handle(&self, mime: &str, _input: &[u8]) -> bool {
+        mime == "application/vnd.openxmlformats-officedocument.presentationml.presentation"
+    }
+
+    fn extract(&self, input: &[u8]) -> Result<String, String> {
+        let mut archive = open_zip(input)?;
+
+        let mut slide_names: Vec<String> = archive
+            //
+            .file_names()
+            //
+            .filter(|name| name.starts_with("ppt/slides/slide") && name.ends_with(".xml"))
+            //
+            .map(|name| name.to_string())
+            //
+            .collect();
+
+        slide_names.sort();
+
+        let mut buf = String::with_capacity(4096);
+
+        for name in slide_names {
+            let Some(xml) = read_zip_entry(&mut archive, &name) else {
+                continue;
+            };
+
+            extract_xml_text(&xml, &["a:p"], &mut buf)?;
+        }
+
+        let effective_len = buf.trim().len();
+        if effective_len < crate::MIN_TEXT_LEN {
+            return Err(format!("invalid text length: {effective_len}"));
+        }
+
+        Ok(buf)
+    }
+}
@@ -0,0 +1,47 @@
+use super::common::{extract_xml_text, open_zip, read_zip_entry};
+use super::Extractor;
+
+pub(crate) struct XlsxExtractor;
+
+impl Extractor for XlsxExtractor {
+    fn can_handle(&self, mime: &str, _input: &[u8]) -> bool {
+        mime == "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+    }
+
+    fn extract(&self, input: &[u8]) -> Result<String, String> {
+        let mut archive = open_zip(input)?;
+
+        let mut sheet_names: Vec<String> = archive
+            //
+            .file_names()
+            //
+            .filter(|name| name.starts_with("xl/worksheets/sheet") && name.ends_with(".xml"))
+            //
+            .map(|name| name.to_string())
+            //
+            .collect();
+
+        sheet_names.sort();
+
+        let mut buf = String::with_capacity(4096);
+
+        if let Some(xml) = read_zip_entry(&mut archive, "xl/sharedStrings.xml") {
+            extract_xml_text(&xml, &["si"], &mut buf)?;
+        }
+
+        for name in sheet_names {
+            let Some(xml) = read_zip_entry(&mut archive, &name) else {
+                continue;
+            };
+
+            extract_xml_text(&xml, &["row"], &mut buf)?;
+        }
+
+        let effective_len = buf.trim().len();
+        if effective_len < crate::MIN_TEXT_LEN {
+            return Err(format!("invalid text length: {effective_len}"));
+        }
+
+        Ok(buf)
+    }
+}
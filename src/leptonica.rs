@@ -0,0 +1,152 @@
+//! Image preprocessing applied to embedded PDF images before they are
+//! handed to Tesseract, to raise OCR accuracy on noisy or low-resolution
+//! scans.
+
+use leptonica_plumbing::Pix;
+
+use crate::alloc::Reservation;
+
+/// Resolution, in pixels on the shorter side, below which an image is
+/// upscaled 2x before OCR.
+const UPSCALE_THRESHOLD_PX: u32 = 800;
+
+/// Whether `preprocess` should run at all. Opt-out via `TE_OCR_PREPROCESS=0`
+/// (enabled by default), for deployments that want the raw decoded image
+/// handed straight to Tesseract.
+pub(crate) fn enabled() -> bool {
+    std::env::var("TE_OCR_PREPROCESS")
+        //
+        .map(|value| value != "0")
+        //
+        .unwrap_or(true)
+}
+
+/// Converts to grayscale, binarizes with Otsu thresholding, upscales small
+/// images, and deskews the result.
+///
+/// Each step can grow the decoded buffer well past the size of the
+/// compressed stream it started from (the 2x upscale alone roughly
+/// quadruples pixel count), so the caller's memory reservation is resized
+/// to match the actual decoded `Pix` after every step rather than being
+/// fixed up front.
+pub(crate) fn preprocess(pix: Pix) -> Result<(Pix, Reservation<'static>), String> {
+    let pix = to_grayscale(pix);
+    let mut reservation = reserve_for(&pix)?;
+
+    let pix = otsu_threshold(pix);
+    reservation = resize_reservation(reservation, &pix)?;
+
+    let pix = if is_low_resolution(&pix) {
+        let scaled = scale_2x(pix);
+        reservation = resize_reservation(reservation, &scaled)?;
+        scaled
+    } else {
+        pix
+    };
+
+    let pix = deskew(pix);
+    reservation = resize_reservation(reservation, &pix)?;
+
+    Ok((pix, reservation))
+}
+
+fn pix_byte_size(pix: &Pix) -> usize {
+    let bytes_per_pixel = (pix.depth() as usize).div_ceil(8).max(1);
+
+    pix.width() as usize * pix.height() as usize * bytes_per_pixel
+}
+
+fn reserve_for(pix: &Pix) -> Result<Reservation<'static>, String> {
+    crate::MEMORY_BUDGET
+        //
+        .try_reserve(pix_byte_size(pix))
+        //
+        .ok_or_else(|| "memory limit exceeded".to_string())
+}
+
+fn resize_reservation(
+    previous: Reservation<'static>,
+    pix: &Pix,
+) -> Result<Reservation<'static>, String> {
+    drop(previous);
+
+    reserve_for(pix)
+}
+
+fn to_grayscale(pix: Pix) -> Pix {
+    pix.convert_rgb_to_gray_2()
+        //
+        .unwrap_or(pix)
+}
+
+fn otsu_threshold(pix: Pix) -> Pix {
+    pix.otsu_adaptive_threshold_2(0, 0, 0, 0, 0.0)
+        //
+        .map(|(thresholded, _)| thresholded)
+        //
+        .unwrap_or(pix)
+}
+
+fn is_low_resolution(pix: &Pix) -> bool {
+    let width = pix.width();
+    let height = pix.height();
+
+    width.min(height) < UPSCALE_THRESHOLD_PX
+}
+
+fn scale_2x(pix: Pix) -> Pix {
+    pix.scale_general(2.0, 2.0, 0.0, 0)
+        //
+        .unwrap_or(pix)
+}
+
+fn deskew(pix: Pix) -> Pix {
+    pix.deskew(0)
+        //
+        .unwrap_or(pix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &[u8] = include_bytes!("../fixtures/tiny_checkerboard.png");
+
+    fn fixture_pix() -> Pix {
+        Pix::read_mem(FIXTURE).expect("fixture image should decode")
+    }
+
+    #[test]
+    fn preprocess_does_not_panic_on_a_small_grayscale_image() {
+        let pix = fixture_pix();
+
+        let (processed, _reservation) = preprocess(pix).expect("preprocessing should succeed");
+
+        assert!(processed.width() > 0);
+        assert!(processed.height() > 0);
+    }
+
+    #[test]
+    fn otsu_threshold_falls_back_to_the_input_on_failure() {
+        let pix = fixture_pix();
+        let width = pix.width();
+
+        let thresholded = otsu_threshold(pix);
+
+        assert_eq!(thresholded.width(), width);
+    }
+
+    #[test]
+    fn scale_2x_doubles_a_low_resolution_image() {
+        let pix = fixture_pix();
+        let width = pix.width();
+        let height = pix.height();
+
+        assert!(is_low_resolution(&pix));
+
+        let scaled = scale_2x(pix);
+
+        assert_eq!(scaled.width(), width * 2);
+        assert_eq!(scaled.height(), height * 2);
+    }
+}